@@ -0,0 +1,37 @@
+//! Compresses the curated puzzle library at build time so it can be baked
+//! directly into the binary via `include_bytes!`, the same way Tauri's own
+//! codegen embeds icons and config at compile time.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    tauri_build::build();
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source = Path::new(&manifest_dir).join("assets/puzzles.txt");
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let raw = fs::read(&source).expect("failed to read bundled puzzle library");
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(&raw)
+        .expect("failed to compress puzzle library");
+    let compressed = encoder.finish().expect("failed to finish puzzle library compression");
+
+    let digest = Sha256::digest(&raw);
+    let digest_hex = digest.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("puzzles.bin"), &compressed)
+        .expect("failed to write compressed puzzle library");
+    fs::write(Path::new(&out_dir).join("puzzles_digest.txt"), digest_hex)
+        .expect("failed to write puzzle library digest");
+}
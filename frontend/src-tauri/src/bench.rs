@@ -0,0 +1,83 @@
+//! `--bench` CLI harness: solves a fixed corpus of puzzles and prints
+//! per-puzzle timings plus aggregate stats as JSON, so regressions in the
+//! DLX solving core show up as numbers instead of a feeling.
+
+use std::time::Instant;
+
+use serde_json::json;
+
+use crate::solver::sudoku;
+
+const CORPUS: &[(&str, &str)] = &[
+    (
+        "easy-1",
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    ),
+    (
+        "medium-1",
+        "020608000580009700000040000370000400600070009008000013000020000009800036000306090",
+    ),
+    (
+        "hard-1",
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    ),
+];
+
+struct Timing {
+    name: &'static str,
+    micros: u128,
+    nodes_visited: u64,
+    solved: bool,
+}
+
+/// Runs the benchmark corpus and prints a JSON report to stdout.
+pub fn run() {
+    let timings: Vec<Timing> = CORPUS
+        .iter()
+        .map(|&(name, puzzle)| {
+            let start = Instant::now();
+            let (solution, nodes_visited) = sudoku::solve_with_nodes(puzzle);
+            Timing {
+                name,
+                micros: start.elapsed().as_micros(),
+                nodes_visited,
+                solved: solution.is_some(),
+            }
+        })
+        .collect();
+
+    let mut sorted_micros: Vec<u128> = timings.iter().map(|t| t.micros).collect();
+    sorted_micros.sort_unstable();
+
+    let report = json!({
+        "puzzles": timings.iter().map(|t| json!({
+            "name": t.name,
+            "micros": t.micros,
+            "nodes_visited": t.nodes_visited,
+            "solved": t.solved,
+        })).collect::<Vec<_>>(),
+        "aggregate": {
+            "count": sorted_micros.len(),
+            "mean_micros": mean(&sorted_micros),
+            "median_micros": percentile(&sorted_micros, 0.50),
+            "p95_micros": percentile(&sorted_micros, 0.95),
+        },
+    });
+
+    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+}
+
+fn mean(sorted: &[u128]) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    sorted.iter().sum::<u128>() as f64 / sorted.len() as f64
+}
+
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx]
+}
@@ -0,0 +1,43 @@
+//! Tauri commands exposing the native solver to the webview over IPC.
+
+use crate::puzzles::{self, PuzzleLibrary};
+use crate::solver::generate::{self, Difficulty, GeneratedPuzzle};
+use crate::solver::sudoku::{self, Hint};
+
+/// Solves `puzzle` and returns the completed grid as an 81-character string.
+#[tauri::command]
+pub fn solve(puzzle: String) -> Result<String, String> {
+    sudoku::solve(&puzzle).ok_or_else(|| "puzzle has no solution".to_string())
+}
+
+/// Checks whether the filled-in cells of `puzzle` violate any Sudoku rule.
+#[tauri::command]
+pub fn validate(puzzle: String) -> bool {
+    sudoku::is_valid(&puzzle)
+}
+
+/// Counts solutions to `puzzle`, capped at 2 so callers can check uniqueness
+/// without paying for an exhaustive search.
+#[tauri::command]
+pub fn count_solutions(puzzle: String) -> usize {
+    sudoku::count_solutions(&puzzle, 2)
+}
+
+/// Returns the next cell to fill and its value, based on solving `puzzle`.
+#[tauri::command]
+pub fn hint(puzzle: String) -> Result<Hint, String> {
+    sudoku::hint(&puzzle).ok_or_else(|| "puzzle is already solved or unsolvable".to_string())
+}
+
+/// Generates a uniquely-solvable puzzle targeting `difficulty`, graded by
+/// the solving techniques it actually requires.
+#[tauri::command]
+pub fn generate(difficulty: Difficulty) -> GeneratedPuzzle {
+    generate::generate(difficulty)
+}
+
+/// Loads the embedded, integrity-checked puzzle library bundled with the app.
+#[tauri::command]
+pub fn load_library() -> Result<PuzzleLibrary, String> {
+    puzzles::load_library()
+}
@@ -1,11 +1,40 @@
 // Sudoku Solver Tauri Application
-// Uses WebAssembly for high-performance puzzle solving
+// Uses a native Rust solver (Dancing Links) over Tauri IPC for
+// high-performance puzzle solving
 
-#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+// `desktop`/`mobile` are cfg aliases that `tauri_build::build()` (see
+// build.rs) sets at compile time; both the manifest and build script must be
+// present for them to mean anything other than "never set".
+#![cfg_attr(all(not(debug_assertions), desktop), windows_subsystem = "windows")]
 
+mod bench;
+mod commands;
+mod puzzles;
+mod solver;
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_shell::init())
+    if std::env::args().any(|arg| arg == "--bench") {
+        bench::run();
+        return;
+    }
+
+    let builder = tauri::Builder::default();
+
+    // The shell plugin shells out to the host OS, which has no equivalent on
+    // mobile, so only wire it up for desktop targets.
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_shell::init());
+
+    builder
+        .invoke_handler(tauri::generate_handler![
+            commands::solve,
+            commands::validate,
+            commands::count_solutions,
+            commands::hint,
+            commands::generate,
+            commands::load_library,
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,7 @@
+// Desktop entry point. Mobile targets call `run()` directly through
+// `#[tauri::mobile_entry_point]` and never build this binary.
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+fn main() {
+    sudoku_solver_lib::run();
+}
@@ -0,0 +1,39 @@
+//! Embedded puzzle library: a curated set of puzzles, compressed and baked
+//! into the binary by `build.rs`, so the frontend can load a daily or
+//! categorized puzzle offline without shipping loose asset files.
+
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+const COMPRESSED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/puzzles.bin"));
+const EXPECTED_DIGEST: &str = include_str!(concat!(env!("OUT_DIR"), "/puzzles_digest.txt"));
+
+#[derive(Serialize)]
+pub struct PuzzleLibrary {
+    pub puzzles: Vec<String>,
+}
+
+/// Decompresses the embedded puzzle library, verifying its integrity digest
+/// before handing any puzzles back to the caller.
+pub fn load_library() -> Result<PuzzleLibrary, String> {
+    let mut raw = Vec::new();
+    GzDecoder::new(COMPRESSED)
+        .read_to_end(&mut raw)
+        .map_err(|e| format!("failed to decompress puzzle library: {e}"))?;
+
+    let digest_hex = Sha256::digest(&raw).iter().map(|b| format!("{b:02x}")).collect::<String>();
+    if digest_hex != EXPECTED_DIGEST {
+        return Err("puzzle library failed integrity check".to_string());
+    }
+
+    let puzzles = String::from_utf8(raw)
+        .map_err(|e| format!("puzzle library is not valid UTF-8: {e}"))?
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    Ok(PuzzleLibrary { puzzles })
+}
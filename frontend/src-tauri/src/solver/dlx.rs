@@ -0,0 +1,192 @@
+//! Generic Dancing Links (Algorithm X) implementation for exact cover problems.
+//!
+//! The matrix is stored as a toroidal doubly-linked list: a row of column
+//! headers threaded left/right, with each column threaded up/down through the
+//! rows that set it. `cover`/`uncover` unlink and relink a column and every
+//! row that intersects it in O(1) per node, which is what makes backtracking
+//! cheap — undoing a choice is just replaying the same links in reverse.
+
+/// Sentinel row id used for column header nodes; never returned from a search.
+const HEADER_ROW: usize = usize::MAX;
+
+pub struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column_of: Vec<usize>,
+    row_of: Vec<usize>,
+    size: Vec<usize>,
+    header: usize,
+    /// Number of times the search descended into a row choice; exposed for benchmarking.
+    pub nodes_visited: u64,
+}
+
+impl Dlx {
+    /// Builds an empty matrix with `num_columns` constraint columns and no rows yet.
+    pub fn new(num_columns: usize) -> Self {
+        let total = num_columns + 1;
+        let mut left = vec![0usize; total];
+        let mut right = vec![0usize; total];
+        let up: Vec<usize> = (0..total).collect();
+        let down: Vec<usize> = (0..total).collect();
+        let column_of: Vec<usize> = (0..total).collect();
+        let row_of = vec![HEADER_ROW; total];
+        let size = vec![0usize; total];
+
+        for c in 0..total {
+            left[c] = if c == 0 { num_columns } else { c - 1 };
+            right[c] = if c == num_columns { 0 } else { c + 1 };
+        }
+
+        Dlx {
+            left,
+            right,
+            up,
+            down,
+            column_of,
+            row_of,
+            size,
+            header: 0,
+            nodes_visited: 0,
+        }
+    }
+
+    /// Adds a row that sets exactly the given columns, tagged with `row_id` so
+    /// a solution (a set of row ids) can be mapped back to the caller's domain.
+    pub fn add_row(&mut self, row_id: usize, columns: &[usize]) {
+        let mut first = None;
+        let mut prev = None;
+
+        for &col in columns {
+            let header = col + 1;
+            let node = self.left.len();
+            self.left.push(node);
+            self.right.push(node);
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            self.column_of.push(header);
+            self.row_of.push(row_id);
+
+            self.down[self.up[header]] = node;
+            self.up[header] = node;
+            self.size[header] += 1;
+
+            if let Some(p) = prev {
+                self.right[p] = node;
+                self.left[node] = p;
+            } else {
+                first = Some(node);
+            }
+            prev = Some(node);
+        }
+
+        if let (Some(first), Some(last)) = (first, prev) {
+            self.right[last] = first;
+            self.left[first] = last;
+        }
+    }
+
+    /// Selects a row outright, as if the search had branched into it, without
+    /// searching. Used to pre-cover a puzzle's given cells before solving.
+    pub fn select_row(&mut self, columns: &[usize]) {
+        for &col in columns {
+            self.cover(col + 1);
+        }
+    }
+
+    fn cover(&mut self, c: usize) {
+        self.right[self.left[c]] = self.right[c];
+        self.left[self.right[c]] = self.left[c];
+
+        let mut i = self.down[c];
+        while i != c {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column_of[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.up[c];
+        while i != c {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column_of[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        self.right[self.left[c]] = c;
+        self.left[self.right[c]] = c;
+    }
+
+    /// Searches for up to `limit` solutions, each returned as the set of row
+    /// ids chosen (including any rows pre-selected via [`Self::select_row`]).
+    pub fn search(&mut self, preselected: Vec<usize>, limit: usize) -> Vec<Vec<usize>> {
+        let mut solutions = Vec::new();
+        let mut partial = preselected;
+        self.search_inner(&mut partial, &mut solutions, limit);
+        solutions
+    }
+
+    fn search_inner(&mut self, partial: &mut Vec<usize>, solutions: &mut Vec<Vec<usize>>, limit: usize) {
+        if solutions.len() >= limit {
+            return;
+        }
+        self.nodes_visited += 1;
+
+        if self.right[self.header] == self.header {
+            solutions.push(partial.clone());
+            return;
+        }
+
+        // S-heuristic: branch on the column with the fewest candidate rows.
+        let mut c = self.right[self.header];
+        let mut best = c;
+        let mut best_size = self.size[c];
+        while c != self.header {
+            if self.size[c] < best_size {
+                best = c;
+                best_size = self.size[c];
+            }
+            c = self.right[c];
+        }
+        let c = best;
+
+        self.cover(c);
+        let mut r = self.down[c];
+        while r != c {
+            partial.push(self.row_of[r]);
+
+            let mut j = self.right[r];
+            while j != r {
+                self.cover(self.column_of[j]);
+                j = self.right[j];
+            }
+
+            self.search_inner(partial, solutions, limit);
+
+            let mut j = self.left[r];
+            while j != r {
+                self.uncover(self.column_of[j]);
+                j = self.left[j];
+            }
+            partial.pop();
+
+            if solutions.len() >= limit {
+                break;
+            }
+            r = self.down[r];
+        }
+        self.uncover(c);
+    }
+}
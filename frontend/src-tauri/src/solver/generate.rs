@@ -0,0 +1,247 @@
+//! Puzzle generation: fill a full grid via a randomized DLX search, dig
+//! holes while keeping the puzzle uniquely solvable, then grade the result
+//! by how far plain constraint propagation gets before DLX has to branch.
+
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use serde::{Deserialize, Serialize};
+
+use super::dlx::Dlx;
+use super::sudoku::{self, Grid, BOX, SIZE};
+
+/// Requested generation difficulty; controls how many givens are left behind.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    fn target_clues(self) -> usize {
+        match self {
+            Difficulty::Easy => 40,
+            Difficulty::Medium => 34,
+            Difficulty::Hard => 28,
+            Difficulty::Expert => 24,
+        }
+    }
+}
+
+/// How hard a generated puzzle actually turned out to be, based on the
+/// solving techniques it requires rather than its clue count alone.
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Grade {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+#[derive(Serialize)]
+pub struct GeneratedPuzzle {
+    pub puzzle: String,
+    pub grade: Grade,
+}
+
+/// Generates a uniquely-solvable puzzle targeting `difficulty`.
+pub fn generate(difficulty: Difficulty) -> GeneratedPuzzle {
+    let solved = fill_solved_grid();
+    let puzzle = dig_holes(solved, difficulty.target_clues());
+    let grade = grade_puzzle(&puzzle);
+    GeneratedPuzzle { puzzle, grade }
+}
+
+/// Fills an empty grid via the DLX search with a randomized digit order, so
+/// repeated calls produce different solved grids.
+fn fill_solved_grid() -> Grid {
+    let mut digits: Vec<usize> = (0..SIZE).collect();
+    digits.shuffle(&mut thread_rng());
+
+    let mut dlx = Dlx::new(sudoku::NUM_COLUMNS);
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            for &d in &digits {
+                dlx.add_row(sudoku::row_id(r, c, d), &sudoku::columns_for(r, c, d));
+            }
+        }
+    }
+
+    let solutions = dlx.search(Vec::new(), 1);
+    sudoku::rows_to_grid(&solutions[0])
+}
+
+/// Removes givens in random order, keeping each removal only if the puzzle
+/// still has exactly one solution, until `target_clues` remain or no more
+/// cells can be safely removed.
+fn dig_holes(solved: Grid, target_clues: usize) -> String {
+    let mut grid = solved;
+    let mut cells: Vec<(usize, usize)> = (0..SIZE).flat_map(|r| (0..SIZE).map(move |c| (r, c))).collect();
+    cells.shuffle(&mut thread_rng());
+
+    let mut clues = SIZE * SIZE;
+    for (r, c) in cells {
+        if clues <= target_clues {
+            break;
+        }
+
+        let given = grid[r][c].take();
+        let candidate = sudoku::render(&grid);
+        if sudoku::count_solutions(&candidate, 2) == 1 {
+            clues -= 1;
+        } else {
+            grid[r][c] = given;
+        }
+    }
+
+    sudoku::render(&grid)
+}
+
+/// Grades a puzzle by how far naked/hidden singles get on their own: solved
+/// by propagation alone is Easy, otherwise the DLX branching node count
+/// buckets it into Medium/Hard/Expert.
+fn grade_puzzle(puzzle: &str) -> Grade {
+    if propagates_to_solution(puzzle) {
+        return Grade::Easy;
+    }
+
+    let (_, nodes_visited) = sudoku::solve_with_nodes(puzzle);
+    match nodes_visited {
+        0..=200 => Grade::Medium,
+        201..=2_000 => Grade::Hard,
+        _ => Grade::Expert,
+    }
+}
+
+/// Repeatedly applies naked-single and hidden-single elimination. Returns
+/// `true` if that alone fills the grid, with no guessing required.
+fn propagates_to_solution(puzzle: &str) -> bool {
+    let Some(mut grid) = sudoku::parse(puzzle) else {
+        return false;
+    };
+
+    loop {
+        let mut changed = apply_naked_singles(&mut grid);
+        changed |= apply_hidden_singles(&mut grid);
+        if !changed {
+            break;
+        }
+    }
+
+    grid.iter().flatten().all(|cell| cell.is_some())
+}
+
+fn apply_naked_singles(grid: &mut Grid) -> bool {
+    let mut changed = false;
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            if grid[r][c].is_some() {
+                continue;
+            }
+            let candidates = candidates_for(grid, r, c);
+            if candidates.len() == 1 {
+                grid[r][c] = Some(candidates[0]);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+fn apply_hidden_singles(grid: &mut Grid) -> bool {
+    let mut changed = false;
+    for unit in units() {
+        for d in 0..SIZE {
+            let mut spot = None;
+            let mut count = 0;
+            for &(r, c) in &unit {
+                if grid[r][c].is_none() && !conflicts(grid, r, c, d) {
+                    count += 1;
+                    spot = Some((r, c));
+                }
+            }
+            if count == 1 {
+                let (r, c) = spot.unwrap();
+                grid[r][c] = Some(d);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+fn candidates_for(grid: &Grid, r: usize, c: usize) -> Vec<usize> {
+    (0..SIZE).filter(|&d| !conflicts(grid, r, c, d)).collect()
+}
+
+fn conflicts(grid: &Grid, r: usize, c: usize, d: usize) -> bool {
+    let digit = Some(d);
+    if grid[r].contains(&digit) || (0..SIZE).any(|i| grid[i][c] == digit) {
+        return true;
+    }
+
+    let (br, bc) = ((r / BOX) * BOX, (c / BOX) * BOX);
+    (0..BOX).any(|dr| (0..BOX).any(|dc| grid[br + dr][bc + dc] == digit))
+}
+
+/// The 27 row/column/box units of a 9x9 grid.
+fn units() -> Vec<Vec<(usize, usize)>> {
+    let mut units = Vec::with_capacity(27);
+    for r in 0..SIZE {
+        units.push((0..SIZE).map(|c| (r, c)).collect());
+    }
+    for c in 0..SIZE {
+        units.push((0..SIZE).map(|r| (r, c)).collect());
+    }
+    for br in 0..BOX {
+        for bc in 0..BOX {
+            units.push(
+                (0..BOX)
+                    .flat_map(|dr| (0..BOX).map(move |dc| (br * BOX + dr, bc * BOX + dc)))
+                    .collect(),
+            );
+        }
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVED: &str =
+        "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+    const EASY: &str =
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+    const HARD: &str =
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400";
+
+    #[test]
+    fn dig_holes_always_leaves_a_unique_solution() {
+        let solved = sudoku::parse(SOLVED).expect("fixture should be a valid solved grid");
+
+        for target in [20, 30, 40, 50] {
+            let puzzle = dig_holes(solved, target);
+            assert_eq!(
+                sudoku::count_solutions(&puzzle, 2),
+                1,
+                "dig_holes produced a non-unique puzzle for target {target}"
+            );
+        }
+    }
+
+    #[test]
+    fn propagates_to_solution_identifies_singles_only_puzzles() {
+        assert!(propagates_to_solution(EASY));
+    }
+
+    #[test]
+    fn propagates_to_solution_rejects_puzzles_needing_backtracking() {
+        assert!(!propagates_to_solution(HARD));
+    }
+}
@@ -0,0 +1,5 @@
+//! Native Sudoku solving core, backed by Knuth's Dancing Links (Algorithm X).
+
+pub mod dlx;
+pub mod generate;
+pub mod sudoku;
@@ -0,0 +1,245 @@
+//! Sudoku-specific exact cover formulation on top of [`super::dlx`].
+//!
+//! A puzzle is represented as an 81-character string read row-major, with
+//! `.` or `0` standing in for a blank cell and `1`-`9` for a given. Every
+//! candidate placement `(row, col, digit)` becomes one exact-cover row that
+//! sets four columns: the cell, row, column and box constraints.
+
+use serde::Serialize;
+
+use super::dlx::Dlx;
+
+pub(crate) const SIZE: usize = 9;
+pub(crate) const BOX: usize = 3;
+pub(crate) const NUM_COLUMNS: usize = SIZE * SIZE * 4;
+
+/// A 9x9 grid of optional digits (`None` for a blank cell).
+pub(crate) type Grid = [[Option<usize>; SIZE]; SIZE];
+
+/// Result of [`hint`]: the next cell to fill and what to put in it.
+#[derive(Serialize)]
+pub struct Hint {
+    pub row: usize,
+    pub col: usize,
+    pub value: u8,
+}
+
+pub(crate) fn row_id(r: usize, c: usize, d: usize) -> usize {
+    (r * SIZE + c) * SIZE + d
+}
+
+pub(crate) fn columns_for(r: usize, c: usize, d: usize) -> [usize; 4] {
+    let cell = r * SIZE + c;
+    let row = SIZE * SIZE + r * SIZE + d;
+    let col = 2 * SIZE * SIZE + c * SIZE + d;
+    let b = (r / BOX) * BOX + c / BOX;
+    let boxc = 3 * SIZE * SIZE + b * SIZE + d;
+    [cell, row, col, boxc]
+}
+
+/// Parses an 81-character puzzle string into a grid of optional digits
+/// (`None` for a blank cell). Returns `None` if the string isn't a valid
+/// 81-cell puzzle.
+pub(crate) fn parse(puzzle: &str) -> Option<Grid> {
+    let chars: Vec<char> = puzzle.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.len() != SIZE * SIZE {
+        return None;
+    }
+
+    let mut grid = [[None; SIZE]; SIZE];
+    for (i, ch) in chars.into_iter().enumerate() {
+        let (r, c) = (i / SIZE, i % SIZE);
+        grid[r][c] = match ch {
+            '.' | '0' => None,
+            '1'..='9' => Some(ch.to_digit(10).unwrap() as usize - 1),
+            _ => return None,
+        };
+    }
+    Some(grid)
+}
+
+pub(crate) fn render(grid: &Grid) -> String {
+    grid.iter()
+        .flat_map(|row| row.iter())
+        .map(|cell| match cell {
+            Some(d) => char::from_digit(*d as u32 + 1, 10).unwrap(),
+            None => '.',
+        })
+        .collect()
+}
+
+pub(crate) fn build_matrix() -> Dlx {
+    let mut dlx = Dlx::new(NUM_COLUMNS);
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            for d in 0..SIZE {
+                dlx.add_row(row_id(r, c, d), &columns_for(r, c, d));
+            }
+        }
+    }
+    dlx
+}
+
+pub(crate) fn preselect_givens(dlx: &mut Dlx, grid: &Grid) -> Vec<usize> {
+    let mut partial = Vec::new();
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &cell) in row.iter().enumerate() {
+            if let Some(d) = cell {
+                dlx.select_row(&columns_for(r, c, d));
+                partial.push(row_id(r, c, d));
+            }
+        }
+    }
+    partial
+}
+
+pub(crate) fn rows_to_grid(rows: &[usize]) -> Grid {
+    let mut grid = [[None; SIZE]; SIZE];
+    for &id in rows {
+        let d = id % SIZE;
+        let c = (id / SIZE) % SIZE;
+        let r = id / (SIZE * SIZE);
+        grid[r][c] = Some(d);
+    }
+    grid
+}
+
+/// Checks that the filled-in cells of `grid` don't violate a row, column or
+/// box constraint.
+fn grid_is_valid(grid: &Grid) -> bool {
+    let mut rows = [[false; SIZE]; SIZE];
+    let mut cols = [[false; SIZE]; SIZE];
+    let mut boxes = [[false; SIZE]; SIZE];
+
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            let Some(d) = grid[r][c] else { continue };
+            let b = (r / BOX) * BOX + c / BOX;
+            if rows[r][d] || cols[c][d] || boxes[b][d] {
+                return false;
+            }
+            rows[r][d] = true;
+            cols[c][d] = true;
+            boxes[b][d] = true;
+        }
+    }
+    true
+}
+
+/// Parses `puzzle` and checks its givens don't already conflict. Returns
+/// `None` for a malformed *or* contradictory puzzle (e.g. the same digit
+/// given twice in a row) — the DLX matrix assumes the rows it pre-covers are
+/// mutually consistent, and pre-covering two conflicting givens corrupts the
+/// toroidal list instead of just failing to find a solution.
+fn validated_grid(puzzle: &str) -> Option<Grid> {
+    let grid = parse(puzzle)?;
+    grid_is_valid(&grid).then_some(grid)
+}
+
+/// Finds and returns the first solution to `puzzle`, or `None` if it has
+/// none (including when its givens already conflict).
+pub fn solve(puzzle: &str) -> Option<String> {
+    let grid = validated_grid(puzzle)?;
+    let mut dlx = build_matrix();
+    let partial = preselect_givens(&mut dlx, &grid);
+    let solutions = dlx.search(partial, 1);
+    solutions.first().map(|rows| render(&rows_to_grid(rows)))
+}
+
+/// Solves `puzzle` like [`solve`], additionally returning the number of
+/// search nodes the DLX core visited. Used by the bench harness to track
+/// how branchy a puzzle is, independent of wall-clock noise.
+pub fn solve_with_nodes(puzzle: &str) -> (Option<String>, u64) {
+    let Some(grid) = validated_grid(puzzle) else {
+        return (None, 0);
+    };
+    let mut dlx = build_matrix();
+    let partial = preselect_givens(&mut dlx, &grid);
+    let solutions = dlx.search(partial, 1);
+    let solved = solutions.first().map(|rows| render(&rows_to_grid(rows)));
+    (solved, dlx.nodes_visited)
+}
+
+/// Counts solutions to `puzzle`, stopping as soon as `cap` are found so a
+/// uniqueness check (`count == 1`) can short-circuit on puzzles with many.
+/// Returns 0 for a malformed or contradictory puzzle.
+pub fn count_solutions(puzzle: &str, cap: usize) -> usize {
+    let Some(grid) = validated_grid(puzzle) else {
+        return 0;
+    };
+    let mut dlx = build_matrix();
+    let partial = preselect_givens(&mut dlx, &grid);
+    dlx.search(partial, cap).len()
+}
+
+/// Checks that the filled-in cells of `puzzle` don't already violate a row,
+/// column or box constraint. Does not require the puzzle to be solvable.
+pub fn is_valid(puzzle: &str) -> bool {
+    match parse(puzzle) {
+        Some(grid) => grid_is_valid(&grid),
+        None => false,
+    }
+}
+
+/// Solves `puzzle` and returns the first blank cell along with the digit it
+/// takes in that solution, or `None` if the puzzle is already full or has no
+/// solution.
+pub fn hint(puzzle: &str) -> Option<Hint> {
+    let grid = parse(puzzle)?;
+    let solved = solve(puzzle)?;
+    let solved_chars: Vec<char> = solved.chars().collect();
+
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            if grid[r][c].is_none() {
+                let value = solved_chars[r * SIZE + c].to_digit(10).unwrap() as u8;
+                return Some(Hint { row: r, col: c, value });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASY: &str =
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+    #[test]
+    fn solves_a_valid_puzzle() {
+        let solution = solve(EASY).expect("puzzle should be solvable");
+        assert!(grid_is_valid(&parse(&solution).unwrap()));
+        assert!(!solution.contains('.'));
+    }
+
+    #[test]
+    fn rejects_conflicting_givens_in_a_row() {
+        let puzzle = format!("55{}", ".".repeat(79));
+        assert!(!is_valid(puzzle.as_str()));
+        assert_eq!(solve(&puzzle), None);
+        assert_eq!(count_solutions(&puzzle, 2), 0);
+        assert!(hint(&puzzle).is_none());
+    }
+
+    #[test]
+    fn rejects_conflicting_givens_in_a_box() {
+        // Same digit twice in the top-left 3x3 box, but different rows/cols.
+        let mut grid = [[None; SIZE]; SIZE];
+        grid[0][0] = Some(0);
+        grid[1][1] = Some(0);
+        let puzzle = render(&grid);
+
+        assert!(!is_valid(&puzzle));
+        assert_eq!(solve(&puzzle), None);
+        assert_eq!(count_solutions(&puzzle, 2), 0);
+    }
+
+    #[test]
+    fn rejects_malformed_puzzle_strings() {
+        assert!(!is_valid("too short"));
+        assert_eq!(solve("too short"), None);
+        assert_eq!(count_solutions("too short", 2), 0);
+    }
+}